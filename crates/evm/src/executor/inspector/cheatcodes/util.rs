@@ -1,9 +1,6 @@
 use super::{ensure, Result};
 use crate::{
-    executor::backend::{
-        error::{DatabaseError, DatabaseResult},
-        DatabaseExt,
-    },
+    executor::backend::error::{DatabaseError, DatabaseResult},
     utils::h256_to_u256_be,
 };
 use alloy_primitives::{Address, Bytes, U256};
@@ -14,12 +11,18 @@ use ethers::{
         k256::{ecdsa::SigningKey, elliptic_curve::bigint::Encoding, Secp256k1},
         Transaction,
     },
-    types::{transaction::eip2718::TypedTransaction, NameOrAddress},
+    types::{
+        transaction::{
+            eip2718::TypedTransaction,
+            eip2930::{AccessList, AccessListItem},
+        },
+        NameOrAddress, H256,
+    },
 };
 use foundry_common::RpcUrl;
 use foundry_utils::types::{ToAlloy, ToEthers};
 use revm::{
-    interpreter::CreateInputs,
+    interpreter::{CallInputs, CreateInputs},
     primitives::{Account, TransactTo},
     Database, EVMData, JournaledState,
 };
@@ -32,11 +35,61 @@ pub const DEFAULT_CREATE2_DEPLOYER: Address = Address::new([
     78, 89, 180, 72, 71, 179, 121, 87, 133, 136, 146, 12, 167, 143, 191, 38, 192, 180, 149, 108,
 ]);
 
+/// The EIP-2718 envelope type of a [`BroadcastableTransaction`], so broadcasting knows which
+/// envelope to sign and serialize as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxType {
+    Legacy,
+    Eip2930,
+    Eip1559,
+}
+
+impl From<&TypedTransaction> for TxType {
+    fn from(tx: &TypedTransaction) -> Self {
+        match tx {
+            TypedTransaction::Legacy(_) => TxType::Legacy,
+            TypedTransaction::Eip2930(_) => TxType::Eip2930,
+            TypedTransaction::Eip1559(_) => TxType::Eip1559,
+        }
+    }
+}
+
+impl Default for TxType {
+    fn default() -> Self {
+        TxType::Legacy
+    }
+}
+
 /// Helps collecting transactions from different forks.
 #[derive(Debug, Clone, Default)]
 pub struct BroadcastableTransaction {
     pub rpc: Option<RpcUrl>,
     pub transaction: TypedTransaction,
+    /// A minimal EIP-2930 access list covering everything touched while simulating this
+    /// transaction, see [`build_access_list`]. `None` if disabled or nothing else was touched.
+    pub access_list: Option<AccessList>,
+    /// The chain id of the fork this transaction was simulated against, for EIP-155 replay
+    /// protection when signing.
+    pub chain_id: Option<u64>,
+    /// Whether the call this transaction was collected from had an explicit gas limit, see
+    /// [`CallGasLimit`].
+    pub was_fixed_gas_limit: bool,
+}
+
+impl BroadcastableTransaction {
+    /// The envelope type this transaction was simulated as, so broadcasting can reproduce it
+    /// exactly instead of defaulting to legacy.
+    pub fn tx_type(&self) -> TxType {
+        TxType::from(&self.transaction)
+    }
+
+    /// Binds `self.transaction` to `self.chain_id`, if one was recorded. Must be called before
+    /// signing.
+    pub fn apply_chain_id(&mut self) {
+        if let Some(chain_id) = self.chain_id {
+            self.transaction.set_chain_id(chain_id);
+        }
+    }
 }
 
 pub type BroadcastableTransactions = VecDeque<BroadcastableTransaction>;
@@ -45,7 +98,10 @@ pub type BroadcastableTransactions = VecDeque<BroadcastableTransaction>;
 pub fn configure_tx_env(env: &mut revm::primitives::Env, tx: &Transaction) {
     env.tx.caller = tx.from.to_alloy();
     env.tx.gas_limit = tx.gas.as_u64();
-    env.tx.gas_price = tx.gas_price.unwrap_or_default().to_alloy();
+    // Bind simulation to the transaction's own chain id, not whatever the env was last set to.
+    env.tx.chain_id = tx.chain_id.map(|id| id.as_u64());
+    // Typed txs report their cap in `max_fee_per_gas`; legacy txs only populate `gas_price`.
+    env.tx.gas_price = tx.max_fee_per_gas.or(tx.gas_price).unwrap_or_default().to_alloy();
     env.tx.gas_priority_fee = tx.max_priority_fee_per_gas.map(|g| g.to_alloy());
     env.tx.nonce = Some(tx.nonce.as_u64());
     env.tx.access_list = tx
@@ -67,6 +123,82 @@ pub fn configure_tx_env(env: &mut revm::primitives::Env, tx: &Transaction) {
         tx.to.map(|tx| tx.to_alloy()).map(TransactTo::Call).unwrap_or_else(TransactTo::create)
 }
 
+/// A snapshot of every account a [`JournaledState`] has loaded, taken so [`build_access_list`]
+/// can diff two points in a script's execution instead of reading the whole-script journal.
+pub type BroadcastCallState = std::collections::HashMap<Address, Account>;
+
+/// Snapshots every account currently loaded in `journaled_state`, for use with
+/// [`build_access_list`].
+pub fn snapshot_state(journaled_state: &JournaledState) -> BroadcastCallState {
+    journaled_state.state().iter().map(|(addr, account)| (*addr, account.clone())).collect()
+}
+
+/// Builds a minimal EIP-2930 access list from whatever changed between `pre_call_state` and
+/// `post_call_state`, i.e. everything touched while simulating one top-level broadcast call.
+/// `sender` and potential precompiles are dropped since both are always warm. Returns `None` if
+/// `enabled` is `false` or nothing else was touched.
+pub fn build_access_list(
+    pre_call_state: &BroadcastCallState,
+    post_call_state: &BroadcastCallState,
+    sender: Address,
+    enabled: bool,
+) -> Option<AccessList> {
+    if !enabled {
+        return None
+    }
+
+    let items = post_call_state
+        .iter()
+        .filter(|(addr, _)| **addr != sender && !is_potential_precompile(**addr))
+        .filter_map(|(addr, account)| {
+            let before = pre_call_state.get(addr);
+            if !account_touched_since(account, before) {
+                return None
+            }
+
+            let storage_keys = account
+                .storage
+                .iter()
+                .filter(|(slot, value)| {
+                    before
+                        .and_then(|before| before.storage.get(*slot))
+                        .map_or(true, |prior| prior.present_value != value.present_value)
+                })
+                .map(|(key, _)| H256::from_uint(&key.to_ethers()))
+                .collect();
+
+            Some(AccessListItem { address: addr.to_ethers(), storage_keys })
+        })
+        .collect::<Vec<_>>();
+
+    if items.is_empty() {
+        None
+    } else {
+        Some(AccessList(items))
+    }
+}
+
+/// Whether `account` differs from its `before` snapshot, i.e. this call (rather than an earlier
+/// one sharing the same journal) is what touched it.
+fn account_touched_since(account: &Account, before: Option<&Account>) -> bool {
+    match before {
+        // Newly present in the journal at all means this call loaded it, even if it's a
+        // value-less CALL/DELEGATECALL/STATICCALL target that never performed an SLOAD.
+        None => true,
+        Some(before) => {
+            account.info.balance != before.info.balance ||
+                account.info.nonce != before.info.nonce ||
+                account.info.code_hash != before.info.code_hash ||
+                account.storage.iter().any(|(slot, value)| {
+                    before
+                        .storage
+                        .get(slot)
+                        .map_or(true, |prior| prior.present_value != value.present_value)
+                })
+        }
+    }
+}
+
 /// Applies the given function `f` to the `revm::Account` belonging to the `addr`
 ///
 /// This will ensure the `Account` is loaded and `touched`, see [`JournaledState::touch`]
@@ -85,6 +217,145 @@ where
     Ok(f(account))
 }
 
+/// An `eth_call`-style state override for a single account, applied before script simulation via
+/// [`apply_state_overrides`]. Every field is optional; unset fields keep the account's existing
+/// value.
+#[derive(Debug, Clone, Default)]
+pub struct StateOverride {
+    pub code: Option<Bytes>,
+    pub balance: Option<U256>,
+    pub nonce: Option<u64>,
+    pub storage: Option<std::collections::HashMap<U256, U256>>,
+}
+
+/// A set of [`StateOverride`]s to apply before simulating a broadcast transaction, keyed by the
+/// account they target.
+pub type StateOverrides = std::collections::HashMap<Address, StateOverride>;
+
+/// Applies a single [`StateOverride`] to `addr` in `journaled_state`, pre-seeding account state
+/// (code/balance/nonce/storage) without it ever having to exist on the underlying `db`.
+pub fn apply_state_override<DB: Database<Error = DatabaseError>>(
+    journaled_state: &mut JournaledState,
+    db: &mut DB,
+    addr: Address,
+    over: &StateOverride,
+) -> DatabaseResult<()> {
+    with_journaled_account(journaled_state, db, addr, |account| {
+        if let Some(balance) = over.balance {
+            account.info.balance = balance;
+        }
+        if let Some(nonce) = over.nonce {
+            account.info.nonce = nonce;
+        }
+        if let Some(code) = &over.code {
+            let bytecode = revm::primitives::Bytecode::new_raw(code.clone()).to_checked();
+            account.info.code_hash = bytecode.hash_slow();
+            account.info.code = Some(bytecode);
+        }
+        if let Some(storage) = &over.storage {
+            for (slot, value) in storage {
+                // Always reset both `original_value` and `present_value`: the override replaces
+                // the slot's baseline, so its gas-refund accounting shouldn't depend on whether
+                // the slot happened to already be loaded.
+                account.storage.insert(*slot, revm::primitives::StorageSlot::new(*value));
+            }
+        }
+    })?;
+
+    Ok(())
+}
+
+/// Applies every [`StateOverride`] in `overrides` to `journaled_state`. See
+/// [`apply_state_override`].
+pub fn apply_state_overrides<DB: Database<Error = DatabaseError>>(
+    overrides: &StateOverrides,
+    journaled_state: &mut JournaledState,
+    db: &mut DB,
+) -> DatabaseResult<()> {
+    for (addr, over) in overrides {
+        apply_state_override(journaled_state, db, *addr, over)?;
+    }
+    Ok(())
+}
+
+/// The one-time deployment account that broadcasts the well-known CREATE2 deployer deployment
+/// transaction, see <https://github.com/Arachnid/deterministic-deployment-proxy>.
+pub const CREATE2_DEPLOYER_SIGNER: Address = Address::new([
+    63, 171, 24, 70, 34, 220, 25, 182, 16, 147, 73, 185, 72, 17, 73, 59, 242, 164, 83, 98,
+]);
+
+/// The runtime bytecode that ends up at [`DEFAULT_CREATE2_DEPLOYER`] once the deployment
+/// transaction above has been submitted by [`CREATE2_DEPLOYER_SIGNER`].
+pub const CREATE2_DEPLOYER_RUNTIME_CODE: &[u8] = &[
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xe0, 0x36, 0x01, 0x60, 0x00, 0x81, 0x60, 0x20, 0x82, 0x37, 0x80, 0x35, 0x82, 0x82, 0x34, 0xf5,
+    0x80, 0x15, 0x15, 0x60, 0x39, 0x57, 0x81, 0x82, 0xfd, 0x5b, 0x80, 0x82, 0x52, 0x50, 0x50, 0x50,
+    0x60, 0x14, 0x60, 0x0c, 0xf3,
+];
+
+/// The raw, pre-signed ("keyless") transaction that deploys [`CREATE2_DEPLOYER_RUNTIME_CODE`] to
+/// [`DEFAULT_CREATE2_DEPLOYER`], broadcast by [`CREATE2_DEPLOYER_SIGNER`]. Must be resubmitted
+/// byte-for-byte via `eth_sendRawTransaction`, not re-signed. See
+/// <https://github.com/Arachnid/deterministic-deployment-proxy>.
+pub const CREATE2_DEPLOYER_DEPLOYMENT_TX: &str = "0xf8a58085174876e800830186a08080b853604580600e600\
+039806000f350fe7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe0360160008160208237\
+8035828234f58015156039578182fd5b8082525050506014600cf31ba022222222222222222222222222222222222222222\
+22222222222222222222222a02222222222222222222222222222222222222222222222222222222222222222";
+
+/// The up-front cost (`gas_price * gas_limit`) of [`CREATE2_DEPLOYER_DEPLOYMENT_TX`], i.e. how
+/// much [`CREATE2_DEPLOYER_SIGNER`] needs to hold before it can broadcast it.
+pub const CREATE2_DEPLOYER_DEPLOYMENT_TX_COST: U256 =
+    U256::from_limbs([10_000_000_000_000_000, 0, 0, 0]);
+
+/// Configuration for the deterministic CREATE2 deployer a script's CREATE2 calls are proxied
+/// through.
+#[derive(Debug, Clone, Copy)]
+pub struct Create2DeployerConfig {
+    /// The deployer address to proxy CREATE2 calls through. Defaults to
+    /// [`DEFAULT_CREATE2_DEPLOYER`].
+    pub address: Address,
+    /// If the configured deployer has no code on the active fork, fund
+    /// [`CREATE2_DEPLOYER_SIGNER`] and arrange for [`CREATE2_DEPLOYER_DEPLOYMENT_TX`] to be
+    /// broadcast instead of failing with `MissingCreate2Deployer`. Only applies when `address`
+    /// is [`DEFAULT_CREATE2_DEPLOYER`].
+    pub auto_bootstrap: bool,
+}
+
+impl Default for Create2DeployerConfig {
+    fn default() -> Self {
+        Self { address: DEFAULT_CREATE2_DEPLOYER, auto_bootstrap: false }
+    }
+}
+
+/// Funds [`CREATE2_DEPLOYER_SIGNER`] and etches [`CREATE2_DEPLOYER_RUNTIME_CODE`] onto `deployer`
+/// for this simulation run, returning the raw deployment tx the caller must broadcast for real.
+fn bootstrap_create2_deployer<DB: Database<Error = DatabaseError>>(
+    deployer: Address,
+    data: &mut EVMData<'_, DB>,
+) -> DatabaseResult<Bytes> {
+    trace!(?deployer, "auto-bootstrapping CREATE2 deployer");
+    apply_state_override(
+        data.journaled_state,
+        data.db,
+        CREATE2_DEPLOYER_SIGNER,
+        &StateOverride { balance: Some(CREATE2_DEPLOYER_DEPLOYMENT_TX_COST), ..Default::default() },
+    )?;
+    apply_state_override(
+        data.journaled_state,
+        data.db,
+        deployer,
+        &StateOverride {
+            code: Some(Bytes::copy_from_slice(CREATE2_DEPLOYER_RUNTIME_CODE)),
+            ..Default::default()
+        },
+    )?;
+    Ok(Bytes::from(
+        ethers::utils::hex::decode(&CREATE2_DEPLOYER_DEPLOYMENT_TX[2..])
+            .expect("CREATE2_DEPLOYER_DEPLOYMENT_TX is valid hex"),
+    ))
+}
+
 pub fn process_create<DB>(
     broadcast_sender: Address,
     bytecode: Bytes,
@@ -94,34 +365,71 @@ pub fn process_create<DB>(
 where
     DB: Database<Error = DatabaseError>,
 {
+    process_create_with_deployer(
+        broadcast_sender,
+        bytecode,
+        &Create2DeployerConfig::default(),
+        data,
+        call,
+    )
+    .map(|(bytecode, to, nonce, _deployment_tx)| (bytecode, to, nonce))
+}
+
+/// Same as [`process_create`], but proxies CREATE2 calls through the deployer configured in
+/// `deployer_config` instead of always assuming [`DEFAULT_CREATE2_DEPLOYER`]. If auto-bootstrap
+/// kicks in, the fourth element of the returned tuple carries the raw deployment tx the caller
+/// must broadcast.
+pub fn process_create_with_deployer<DB>(
+    broadcast_sender: Address,
+    bytecode: Bytes,
+    deployer_config: &Create2DeployerConfig,
+    data: &mut EVMData<'_, DB>,
+    call: &mut CreateInputs,
+) -> DatabaseResult<(Bytes, Option<NameOrAddress>, u64, Option<Bytes>)>
+where
+    DB: Database<Error = DatabaseError>,
+{
+    let create2_deployer = deployer_config.address;
+    let can_auto_bootstrap =
+        deployer_config.auto_bootstrap && create2_deployer == DEFAULT_CREATE2_DEPLOYER;
+    if deployer_config.auto_bootstrap && !can_auto_bootstrap {
+        trace!(
+            create2=?create2_deployer,
+            "auto_bootstrap is a no-op for a custom CREATE2 deployer address"
+        );
+    }
+
     match call.scheme {
         revm::primitives::CreateScheme::Create => {
             call.caller = broadcast_sender;
 
-            Ok((bytecode, None, data.journaled_state.account(broadcast_sender).info.nonce))
+            Ok((bytecode, None, data.journaled_state.account(broadcast_sender).info.nonce, None))
         }
         revm::primitives::CreateScheme::Create2 { salt } => {
             // Sanity checks for our CREATE2 deployer
-            data.journaled_state.load_account(DEFAULT_CREATE2_DEPLOYER, data.db)?;
-
-            let info = &data.journaled_state.account(DEFAULT_CREATE2_DEPLOYER).info;
-            match &info.code {
-                Some(code) => {
-                    if code.is_empty() {
-                        trace!(create2=?DEFAULT_CREATE2_DEPLOYER, "Empty Create 2 deployer code");
-                        return Err(DatabaseError::MissingCreate2Deployer)
-                    }
-                }
+            data.journaled_state.load_account(create2_deployer, data.db)?;
+
+            let missing = match &data.journaled_state.account(create2_deployer).info.code {
+                Some(code) => code.is_empty(),
                 None => {
                     // forked db
-                    trace!(create2=?DEFAULT_CREATE2_DEPLOYER, "Missing Create 2 deployer code");
-                    if data.db.code_by_hash(info.code_hash)?.is_empty() {
-                        return Err(DatabaseError::MissingCreate2Deployer)
-                    }
+                    let code_hash = data.journaled_state.account(create2_deployer).info.code_hash;
+                    data.db.code_by_hash(code_hash)?.is_empty()
                 }
-            }
+            };
+
+            let deployment_tx = if missing {
+                if can_auto_bootstrap {
+                    Some(bootstrap_create2_deployer(create2_deployer, data)?)
+                } else {
+                    trace!(create2=?create2_deployer, "Missing Create 2 deployer code");
+                    return Err(DatabaseError::MissingCreate2Deployer)
+                }
+            } else {
+                None
+            };
 
-            call.caller = DEFAULT_CREATE2_DEPLOYER;
+            call.caller = create2_deployer;
 
             // We have to increment the nonce of the user address, since this create2 will be done
             // by the create2_deployer
@@ -139,8 +447,9 @@ where
 
             Ok((
                 calldata.freeze().into(),
-                Some(NameOrAddress::Address(DEFAULT_CREATE2_DEPLOYER.to_ethers())),
+                Some(NameOrAddress::Address(create2_deployer.to_ethers())),
                 nonce,
+                deployment_tx,
             ))
         }
     }
@@ -157,24 +466,280 @@ pub fn parse_private_key(private_key: U256) -> Result<SigningKey> {
     SigningKey::from_bytes((&bytes).into()).map_err(Into::into)
 }
 
-// Determines if the gas limit on a given call was manually set in the script and should therefore
-// not be overwritten by later estimations
-pub fn check_if_fixed_gas_limit<DB: DatabaseExt>(
-    data: &EVMData<'_, DB>,
-    call_gas_limit: u64,
-) -> bool {
-    // If the gas limit was not set in the source code it is set to the estimated gas left at the
-    // time of the call, which should be rather close to configured gas limit.
-    // TODO: Find a way to reliably make this determination. (for example by
-    // generating it in the compilation or evm simulation process)
-    U256::from(data.env.tx.gas_limit) > data.env.block.gas_limit &&
-        U256::from(call_gas_limit) <= data.env.block.gas_limit
-        // Transfers in forge scripts seem to be estimated at 2300 by revm leading to "Intrinsic
-        // gas too low" failure when simulated on chain
-        && call_gas_limit > 2300
+/// A call's gas limit as recorded during script simulation, carried through to
+/// [`BroadcastableTransaction`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CallGasLimit {
+    pub gas_limit: u64,
+    /// `true` if the script explicitly set this call's gas limit (e.g. `addr.call{gas: N}(...)`),
+    /// as determined by [`was_explicit_gas_arg`] rather than inferred from `gas_limit` itself.
+    pub was_explicit: bool,
+}
+
+impl CallGasLimit {
+    /// Records a call's (post-EIP-150) `gas_limit` together with its already-determined
+    /// explicitness.
+    pub fn new(gas_limit: u64, was_explicit: bool) -> Self {
+        Self { gas_limit, was_explicit }
+    }
+
+    /// Captures a [`CallGasLimit`] from `CallInputs`, given the explicitness already determined
+    /// at the call site, see [`was_explicit_gas_arg`].
+    pub fn from_call_inputs(call: &CallInputs, was_explicit: bool) -> Self {
+        Self::new(call.gas_limit, was_explicit)
+    }
+}
+
+/// Whether a call's raw `gas` stack argument (`requested_gas`, read by a `step` hook before
+/// EIP-150 forwarding is applied) was explicit, rather than solc's default of requesting
+/// everything left via the `GAS` opcode (`gas_remaining`, at that same point).
+pub fn was_explicit_gas_arg(requested_gas: U256, gas_remaining: u64) -> bool {
+    requested_gas != U256::from(gas_remaining)
 }
 
 /// Small utility function that checks if an address is a potential precompile.
 pub fn is_potential_precompile(address: Address) -> bool {
     address < Address::with_last_byte(10) && address != Address::ZERO
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_chain_id_is_noop_without_a_recorded_chain_id() {
+        let mut broadcastable = BroadcastableTransaction {
+            transaction: TypedTransaction::Legacy(Default::default()),
+            chain_id: None,
+            ..Default::default()
+        };
+
+        broadcastable.apply_chain_id();
+
+        assert_eq!(broadcastable.transaction.chain_id(), None);
+    }
+
+    #[test]
+    fn apply_chain_id_binds_the_recorded_chain_id() {
+        let mut broadcastable = BroadcastableTransaction {
+            transaction: TypedTransaction::Legacy(Default::default()),
+            chain_id: Some(31337),
+            ..Default::default()
+        };
+
+        broadcastable.apply_chain_id();
+
+        assert_eq!(broadcastable.transaction.chain_id(), Some(31337u64.into()));
+    }
+
+    #[test]
+    fn configure_tx_env_falls_back_to_gas_price_for_legacy_txs() {
+        let mut env = revm::primitives::Env::default();
+        let tx = Transaction {
+            gas_price: Some(5u64.into()),
+            max_fee_per_gas: None,
+            ..Default::default()
+        };
+
+        configure_tx_env(&mut env, &tx);
+
+        assert_eq!(env.tx.gas_price, U256::from(5));
+    }
+
+    #[test]
+    fn configure_tx_env_prefers_max_fee_per_gas_for_1559_txs() {
+        let mut env = revm::primitives::Env::default();
+        let tx = Transaction {
+            gas_price: Some(5u64.into()),
+            max_fee_per_gas: Some(10u64.into()),
+            ..Default::default()
+        };
+
+        configure_tx_env(&mut env, &tx);
+
+        assert_eq!(env.tx.gas_price, U256::from(10));
+    }
+
+    fn account_with_storage(slots: &[(U256, U256)]) -> Account {
+        let mut account = Account::default();
+        for (slot, value) in slots {
+            account.storage.insert(*slot, revm::primitives::StorageSlot::new(*value));
+        }
+        account
+    }
+
+    #[test]
+    fn access_list_does_not_include_an_earlier_calls_state() {
+        let sender = Address::with_last_byte(1);
+        let first_target = Address::with_last_byte(2);
+        let second_target = Address::with_last_byte(3);
+        let slot_a = U256::from(1);
+        let slot_b = U256::from(2);
+
+        // First broadcast call touches `first_target`.
+        let after_first: BroadcastCallState =
+            [(first_target, account_with_storage(&[(slot_a, U256::from(100))]))].into();
+
+        // Second call starts from that journal and only touches `second_target`.
+        let pre_second = after_first.clone();
+        let mut after_second = after_first;
+        after_second.insert(second_target, account_with_storage(&[(slot_b, U256::from(200))]));
+
+        let access_list = build_access_list(&pre_second, &after_second, sender, true)
+            .expect("second call touched new state");
+
+        assert_eq!(access_list.0.len(), 1);
+        assert_eq!(access_list.0[0].address, second_target.to_ethers());
+        assert_eq!(access_list.0[0].storage_keys, vec![H256::from_uint(&slot_b.to_ethers())]);
+    }
+
+    #[test]
+    fn access_list_includes_no_storage_staticcall_target() {
+        let sender = Address::with_last_byte(1);
+        let oracle = Address::with_last_byte(2);
+
+        // A STATICCALL target that's only read via e.g. `latestPrice()` with no SLOADs still
+        // gets loaded into the journal, but never `touch()`ed and never gains any storage.
+        let pre_call = BroadcastCallState::new();
+        let post_call: BroadcastCallState = [(oracle, Account::default())].into();
+
+        let access_list = build_access_list(&pre_call, &post_call, sender, true)
+            .expect("staticcall target should still be included");
+
+        assert_eq!(access_list.0.len(), 1);
+        assert_eq!(access_list.0[0].address, oracle.to_ethers());
+        assert!(access_list.0[0].storage_keys.is_empty());
+    }
+
+    /// A [`Database`] that never has anything pre-existing on it, so every account starts out
+    /// empty and `apply_state_override`'s behavior is driven entirely by the override itself.
+    struct EmptyTestDb;
+
+    impl Database for EmptyTestDb {
+        type Error = DatabaseError;
+
+        fn basic(
+            &mut self,
+            _address: Address,
+        ) -> Result<Option<revm::primitives::AccountInfo>, Self::Error> {
+            Ok(None)
+        }
+
+        fn code_by_hash(
+            &mut self,
+            _code_hash: alloy_primitives::B256,
+        ) -> Result<revm::primitives::Bytecode, Self::Error> {
+            Ok(revm::primitives::Bytecode::default())
+        }
+
+        fn storage(&mut self, _address: Address, _index: U256) -> Result<U256, Self::Error> {
+            Ok(U256::ZERO)
+        }
+
+        fn block_hash(&mut self, _number: U256) -> Result<alloy_primitives::B256, Self::Error> {
+            Ok(alloy_primitives::B256::ZERO)
+        }
+    }
+
+    #[test]
+    fn apply_state_override_sets_code_balance_nonce_and_storage() {
+        let mut journaled_state = JournaledState::new(revm::primitives::SpecId::LATEST, vec![]);
+        let mut db = EmptyTestDb;
+        let addr = Address::with_last_byte(1);
+        let slot = U256::from(1);
+
+        // Pre-load the slot via a plain write so it's already present (and therefore already has
+        // an `original_value`) by the time the override is applied.
+        with_journaled_account(&mut journaled_state, &mut db, addr, |account| {
+            account.storage.insert(slot, revm::primitives::StorageSlot::new(U256::from(100)));
+        })
+        .unwrap();
+
+        let over = StateOverride {
+            code: Some(Bytes::from_static(&[0x60, 0x00])),
+            balance: Some(U256::from(1_000)),
+            nonce: Some(7),
+            storage: Some([(slot, U256::from(200))].into()),
+        };
+        apply_state_override(&mut journaled_state, &mut db, addr, &over).unwrap();
+
+        let account = journaled_state.state.get(&addr).unwrap();
+        assert_eq!(account.info.balance, U256::from(1_000));
+        assert_eq!(account.info.nonce, 7);
+        assert!(account.info.code.is_some());
+
+        let slot = account.storage.get(&slot).unwrap();
+        assert_eq!(slot.present_value, U256::from(200));
+        assert_eq!(
+            slot.original_value,
+            U256::from(200),
+            "a previously-loaded slot's original_value must be reset too, not just present_value"
+        );
+    }
+
+    #[test]
+    fn create2_deployer_deployment_tx_recovers_expected_signer() {
+        use ethers::core::k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+        let raw = ethers::utils::hex::decode(&CREATE2_DEPLOYER_DEPLOYMENT_TX[2..])
+            .expect("CREATE2_DEPLOYER_DEPLOYMENT_TX is valid hex");
+        assert_eq!(raw.len(), 167, "unexpected length for the well-known deployment tx");
+
+        let rlp = ethers::utils::rlp::Rlp::new(&raw);
+        assert_eq!(rlp.item_count().unwrap(), 9, "legacy tx has 9 rlp fields");
+        let field = |i: usize| rlp.at(i).unwrap().data().unwrap().to_vec();
+        let (nonce, gas_price, gas_limit, to, value, data, v, r, s) = (
+            field(0),
+            field(1),
+            field(2),
+            field(3),
+            field(4),
+            field(5),
+            field(6),
+            field(7),
+            field(8),
+        );
+
+        // Recompute the signing hash from the unsigned (pre-EIP-155) 6-field encoding.
+        let mut stream = ethers::utils::rlp::RlpStream::new_list(6);
+        for item in [&nonce, &gas_price, &gas_limit, &to, &value, &data] {
+            stream.append(item);
+        }
+        let sighash = ethers::utils::keccak256(stream.out());
+
+        let v = v.first().copied().unwrap_or_default() as u64;
+        let recid = RecoveryId::from_byte((v - 27) as u8).expect("valid recovery id");
+        let mut rs = [0u8; 64];
+        rs[32 - r.len()..32].copy_from_slice(&r);
+        rs[64 - s.len()..64].copy_from_slice(&s);
+        let sig = Signature::from_slice(&rs).expect("valid signature");
+
+        let recovered = VerifyingKey::recover_from_prehash(&sighash, &sig, recid)
+            .expect("signature recovers to a public key");
+        let uncompressed = recovered.to_encoded_point(false);
+        let signer = ethers::utils::keccak256(&uncompressed.as_bytes()[1..]);
+
+        assert_eq!(&signer[12..], CREATE2_DEPLOYER_SIGNER.as_slice());
+    }
+
+    #[test]
+    fn access_list_disabled_is_none() {
+        let state = BroadcastCallState::new();
+        assert!(build_access_list(&state, &state, Address::ZERO, false).is_none());
+    }
+
+    #[test]
+    fn gas_arg_matching_remaining_is_not_explicit() {
+        assert!(!was_explicit_gas_arg(U256::from(6_400), 6_400));
+    }
+
+    #[test]
+    fn gas_arg_equal_to_63_64_of_remaining_is_still_explicit() {
+        let gas_remaining = 6_400;
+        let default_forwarded = gas_remaining - gas_remaining / 64;
+        // The script explicitly asked for exactly what EIP-150 would have forwarded by default,
+        // so the post-EIP-150 gas limit alone can't tell it apart from an implicit call; the raw
+        // `gas` argument still differs from `gas_remaining`.
+        assert!(was_explicit_gas_arg(U256::from(default_forwarded), gas_remaining));
+    }
+}